@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bb8::PooledConnection;
+use r2d2::ManageConnection;
+use tokio::sync::Mutex;
+
+use crate::cluster::ConnectionPool;
+use crate::error;
+
+/// How a [`ClusterConnectionPool`] should pick which node's pool serves the next request.
+#[derive(Debug, Clone, Copy)]
+pub enum LoadBalancingStrategy {
+    /// Cycle through the node pools in order.
+    RoundRobin,
+    /// Pick a node pool uniformly at random.
+    Random,
+    /// Placeholder for routing by the partition key's token range. Currently falls back to
+    /// `RoundRobin` until the driver tracks token ownership per node.
+    TokenAware,
+}
+
+/// How long a node pool is skipped after its last checkout failed, before being retried.
+const COOLDOWN: Duration = Duration::from_secs(10);
+
+struct NodeHealth {
+    last_failure: Mutex<Option<Instant>>,
+}
+
+impl NodeHealth {
+    fn new() -> Self {
+        NodeHealth {
+            last_failure: Mutex::new(None),
+        }
+    }
+
+    async fn is_cooling_down(&self) -> bool {
+        match *self.last_failure.lock().await {
+            Some(at) => at.elapsed() < COOLDOWN,
+            None => false,
+        }
+    }
+
+    async fn mark_failed(&self) {
+        *self.last_failure.lock().await = Some(Instant::now());
+    }
+
+    async fn mark_succeeded(&self) {
+        *self.last_failure.lock().await = None;
+    }
+}
+
+/// A pool of pools spanning every contact point in a cluster.
+///
+/// Wraps one [`ConnectionPool`] per node and, on [`get`](Self::get), picks a node per the
+/// configured [`LoadBalancingStrategy`], skipping nodes still in cooldown from a prior failure.
+pub struct ClusterConnectionPool<M: ManageConnection> {
+    pools: Vec<ConnectionPool<M>>,
+    health: Vec<NodeHealth>,
+    strategy: LoadBalancingStrategy,
+    next: AtomicUsize,
+}
+
+impl<M: ManageConnection> ClusterConnectionPool<M> {
+    pub fn new(pools: Vec<ConnectionPool<M>>, strategy: LoadBalancingStrategy) -> error::Result<Self> {
+        if pools.is_empty() {
+            return Err(error::Error::from(
+                "Cannot build a ClusterConnectionPool with no node pools",
+            ));
+        }
+
+        let health = pools.iter().map(|_| NodeHealth::new()).collect();
+
+        Ok(ClusterConnectionPool {
+            pools,
+            health,
+            strategy,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Checks out a pooled connection from one of the cluster's node pools, chosen per the
+    /// configured [`LoadBalancingStrategy`]. Returns an aggregated error listing every node's
+    /// failure (or cooldown) if all of them are unavailable.
+    pub async fn get(&self) -> error::Result<PooledConnection<'_, M>> {
+        let order = self.candidate_order();
+        let mut failures = Vec::new();
+
+        for index in order {
+            if self.health[index].is_cooling_down().await {
+                failures.push(format!("node {}: skipped, still in cooldown", index));
+                continue;
+            }
+
+            match self.pools[index].get().await {
+                Ok(conn) => {
+                    self.health[index].mark_succeeded().await;
+                    return Ok(conn);
+                }
+                Err(err) => {
+                    self.health[index].mark_failed().await;
+                    failures.push(format!("node {}: {}", index, err));
+                }
+            }
+        }
+
+        Err(error::Error::from(format!(
+            "All cluster nodes are unavailable: {}",
+            failures.join("; ")
+        )))
+    }
+
+    /// Order in which node pools should be tried for the next `get()`, per the configured
+    /// [`LoadBalancingStrategy`].
+    fn candidate_order(&self) -> Vec<usize> {
+        let len = self.pools.len();
+
+        let start = match self.strategy {
+            LoadBalancingStrategy::RoundRobin | LoadBalancingStrategy::TokenAware => {
+                self.next.fetch_add(1, Ordering::Relaxed) % len
+            }
+            LoadBalancingStrategy::Random => rand::random::<usize>() % len,
+        };
+
+        (0..len).map(|offset| (start + offset) % len).collect()
+    }
+}
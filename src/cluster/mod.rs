@@ -0,0 +1,77 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use bb8::PooledConnection;
+use r2d2::{ManageConnection, Pool};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::sync::Mutex;
+
+use crate::authenticators::Authenticator;
+use crate::compression::Compression;
+use crate::error;
+use crate::frame::parser::parse_frame;
+use crate::frame::{Frame, IntoBytes};
+
+pub mod cluster_connection_pool;
+pub mod multiplexer;
+pub mod root_cert_source;
+#[cfg(feature = "rustls")]
+pub mod rustls_connection_pool;
+pub mod ssl_connection_pool;
+
+pub use cluster_connection_pool::{ClusterConnectionPool, LoadBalancingStrategy};
+pub use root_cert_source::RootCertSource;
+#[cfg(feature = "rustls")]
+pub use rustls_connection_pool::{new_rustls_pool, RustlsConnectionPool, RustlsConnectionsManager};
+pub use ssl_connection_pool::{new_ssl_pool, SslConnectionPool, SslConnectionsManager};
+
+/// Configuration for a single node's TLS-encrypted connection pool.
+pub struct NodeSslConfig<'a, A> {
+    pub addr: &'a str,
+    pub authenticator: A,
+    pub max_size: u32,
+    pub min_idle: Option<u32>,
+    pub max_lifetime: Option<Duration>,
+    pub idle_timeout: Option<Duration>,
+    pub connection_timeout: Duration,
+    pub handshake_timeout: Duration,
+    pub root_certs: RootCertSource,
+}
+
+/// Wraps a single node's connection pool together with the address it was built for.
+pub struct ConnectionPool<M: ManageConnection> {
+    pool: Pool<M>,
+    addr: SocketAddr,
+}
+
+impl<M: ManageConnection> ConnectionPool<M> {
+    pub fn new(pool: Pool<M>, addr: SocketAddr) -> Self {
+        ConnectionPool { pool, addr }
+    }
+
+    /// The node this pool holds connections to.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Checks out a pooled connection to this node.
+    pub async fn get(&self) -> error::Result<PooledConnection<'_, M>> {
+        self.pool
+            .get()
+            .await
+            .map_err(|err| error::Error::from(err.to_string()))
+    }
+}
+
+/// Runs the CQL STARTUP handshake over a freshly-connected transport.
+pub async fn startup<A, T>(transport: &Mutex<T>, _auth: &A) -> error::Result<()>
+where
+    A: Authenticator,
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let startup_frame = Frame::new_req_startup(None).into_cbytes();
+    transport.lock().await.write_all(startup_frame.as_slice()).await?;
+
+    parse_frame(transport, &Compression::None {}).await?;
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI16, Ordering};
+use std::sync::Arc;
+
+use tokio::io::{split, AsyncRead, AsyncWrite, AsyncWriteExt, ReadHalf, WriteHalf};
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+use crate::compression::Compression;
+use crate::error;
+use crate::frame::parser::parse_frame;
+use crate::frame::{Frame, IntoBytes};
+
+/// Stream id the CQL native protocol reserves for server-initiated frames (e.g. events) that
+/// are not a response to any request this driver sent.
+const EVENT_STREAM_ID: i16 = -1;
+
+type PendingMap = HashMap<i16, oneshot::Sender<error::Result<Frame>>>;
+
+/// Multiplexes many concurrent `query`/`execute` calls over a single CQL connection, routed by
+/// the 2-byte stream id in the frame header.
+pub struct MultiplexedConnection<T> {
+    next_stream_id: AtomicI16,
+    pending: Arc<Mutex<PendingMap>>,
+    writer: Mutex<WriteHalf<T>>,
+    dead: Arc<AtomicBool>,
+}
+
+impl<T> MultiplexedConnection<T>
+where
+    T: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+{
+    /// Splits `transport` and spawns the reader loop over it. Server-initiated frames (stream
+    /// id `-1`) are delivered on the returned channel instead.
+    pub fn new(transport: T, compression: Compression) -> (Self, mpsc::UnboundedReceiver<Frame>) {
+        let (read_half, write_half) = split(transport);
+        let pending: Arc<Mutex<PendingMap>> = Arc::new(Mutex::new(HashMap::new()));
+        let dead = Arc::new(AtomicBool::new(false));
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(Self::reader_loop(
+            read_half,
+            compression,
+            pending.clone(),
+            dead.clone(),
+            events_tx,
+        ));
+
+        let connection = MultiplexedConnection {
+            next_stream_id: AtomicI16::new(0),
+            pending,
+            writer: Mutex::new(write_half),
+            dead,
+        };
+
+        (connection, events_rx)
+    }
+
+    /// Whether a write on this connection has failed or the reader loop has shut down after an
+    /// I/O or parse error; `send` then refuses new requests on this connection.
+    pub fn is_dead(&self) -> bool {
+        self.dead.load(Ordering::Relaxed)
+    }
+
+    /// Sends `frame` over the shared connection and awaits the response matching its stream id.
+    pub async fn send(&self, mut frame: Frame) -> error::Result<Frame> {
+        if self.is_dead() {
+            return Err(error::Error::from("Connection is dead"));
+        }
+
+        let (stream_id, response) = {
+            let mut pending = self.pending.lock().await;
+            let stream_id = self.allocate_stream_id(&pending)?;
+            let (tx, rx) = oneshot::channel();
+            pending.insert(stream_id, tx);
+            (stream_id, rx)
+        };
+
+        frame.stream_id = stream_id;
+        let bytes = frame.into_cbytes();
+
+        if let Err(err) = self.writer.lock().await.write_all(bytes.as_slice()).await {
+            self.dead.store(true, Ordering::Relaxed);
+            self.pending.lock().await.remove(&stream_id);
+            return Err(error::Error::from(err.to_string()));
+        }
+
+        response
+            .await
+            .map_err(|_| error::Error::from("Connection closed while awaiting response"))?
+    }
+
+    /// Picks a free stream id, skipping any still awaiting a response, and wraps back to `0`
+    /// once `i16::MAX` is reached. Negative ids are reserved for server-initiated frames.
+    fn allocate_stream_id(&self, pending: &PendingMap) -> error::Result<i16> {
+        for _ in 0..=i16::MAX {
+            let id = self.next_stream_id.fetch_add(1, Ordering::Relaxed) & i16::MAX;
+            if !pending.contains_key(&id) {
+                return Ok(id);
+            }
+        }
+
+        Err(error::Error::from(
+            "No free CQL stream ids available: connection is saturated",
+        ))
+    }
+
+    async fn reader_loop(
+        read_half: ReadHalf<T>,
+        compression: Compression,
+        pending: Arc<Mutex<PendingMap>>,
+        dead: Arc<AtomicBool>,
+        events: mpsc::UnboundedSender<Frame>,
+    ) {
+        let read_half = Mutex::new(read_half);
+
+        loop {
+            match parse_frame(&read_half, &compression).await {
+                Ok(frame) if frame.stream_id == EVENT_STREAM_ID => {
+                    let _ = events.send(frame);
+                }
+                Ok(frame) => {
+                    if let Some(sender) = pending.lock().await.remove(&frame.stream_id) {
+                        let _ = sender.send(Ok(frame));
+                    }
+                }
+                Err(err) => {
+                    dead.store(true, Ordering::Relaxed);
+
+                    let message = err.to_string();
+                    for (_, sender) in pending.lock().await.drain() {
+                        let _ = sender.send(Err(error::Error::from(message.clone())));
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use rustls::{Certificate, OwnedTrustAnchor, RootCertStore};
+
+use crate::error;
+
+/// Where a [`NodeSslConfig`](super::NodeSslConfig) should source the root certificates it
+/// trusts when verifying a Cassandra node's TLS certificate.
+#[derive(Debug, Clone)]
+pub enum RootCertSource {
+    /// Trust the anchors the operating system already trusts.
+    Native,
+    /// Trust the compiled-in Mozilla root bundle shipped via `webpki-roots`.
+    WebpkiBundled,
+    /// Trust the CA certificates contained in a PEM file. `new_ssl_pool` reads the file once, at
+    /// pool construction; `new_rustls_pool`'s manager reads it again for each new connection.
+    Pem(PathBuf),
+    /// Trust an already-parsed set of certificates.
+    Custom(Vec<Certificate>),
+}
+
+impl Default for RootCertSource {
+    fn default() -> Self {
+        RootCertSource::WebpkiBundled
+    }
+}
+
+impl RootCertSource {
+    /// Builds a `rustls::RootCertStore` populated according to this source, for use by
+    /// [`new_rustls_pool`](super::new_rustls_pool).
+    pub fn to_rustls_store(&self) -> error::Result<RootCertStore> {
+        let mut store = RootCertStore::empty();
+
+        match self {
+            RootCertSource::Native => {
+                for cert in rustls_native_certs::load_native_certs()? {
+                    // A handful of OS-bundled certs are not valid trust anchors; skip those
+                    // instead of aborting the whole load.
+                    let _ = store.add(&Certificate(cert.0));
+                }
+            }
+            RootCertSource::WebpkiBundled => {
+                store.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|ta| {
+                    OwnedTrustAnchor::from_subject_spki_name_constraints(
+                        ta.subject,
+                        ta.spki,
+                        ta.name_constraints,
+                    )
+                }));
+            }
+            RootCertSource::Pem(path) => {
+                let file = File::open(path)?;
+                let mut reader = BufReader::new(file);
+                let certs = rustls_pemfile::certs(&mut reader)
+                    .map_err(|_| error::Error::from("Cannot parse PEM root certificate bundle"))?;
+
+                for cert in certs {
+                    let _ = store.add(&Certificate(cert));
+                }
+            }
+            RootCertSource::Custom(certs) => {
+                for cert in certs {
+                    let _ = store.add(cert);
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    /// Builds an `openssl::x509::store::X509Store` populated according to this source, for use
+    /// by [`new_ssl_pool`](super::new_ssl_pool).
+    pub fn to_openssl_store(&self) -> error::Result<openssl::x509::store::X509Store> {
+        let mut builder = openssl::x509::store::X509StoreBuilder::new()?;
+
+        match self {
+            RootCertSource::Native => {
+                builder.set_default_paths()?;
+            }
+            RootCertSource::WebpkiBundled => {
+                // `webpki_roots::TrustAnchor` only carries the subject/SPKI/name-constraints
+                // triple rustls needs, not a full DER certificate, so it can't be fed to
+                // `X509::from_der` directly. `webpki-root-certs` packages the same Mozilla
+                // bundle as complete DER certificates for exactly this kind of bridging.
+                for der in webpki_root_certs::TLS_SERVER_ROOT_CERTS {
+                    let x509 = openssl::x509::X509::from_der(der)?;
+                    builder.add_cert(x509)?;
+                }
+            }
+            RootCertSource::Pem(path) => {
+                let pem = std::fs::read(path)?;
+                for cert in openssl::x509::X509::stack_from_pem(&pem)? {
+                    builder.add_cert(cert)?;
+                }
+            }
+            RootCertSource::Custom(certs) => {
+                for cert in certs {
+                    let x509 = openssl::x509::X509::from_der(&cert.0)?;
+                    builder.add_cert(x509)?;
+                }
+            }
+        }
+
+        Ok(builder.build())
+    }
+}
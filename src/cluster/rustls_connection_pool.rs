@@ -0,0 +1,148 @@
+#![cfg(feature = "rustls")]
+
+use std::convert::TryFrom;
+use std::net::ToSocketAddrs;
+use std::sync::Arc;
+use std::time::Duration;
+
+use r2d2::{Builder, ManageConnection, Pool};
+use rustls::{ClientConfig, ServerName};
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio_rustls::TlsConnector;
+
+use crate::authenticators::Authenticator;
+use crate::cluster::ConnectionPool;
+use crate::cluster::{startup, NodeSslConfig};
+use crate::cluster::RootCertSource;
+use crate::compression::Compression;
+use crate::error;
+use crate::frame::parser::parse_frame;
+use crate::frame::{Frame, IntoBytes};
+use crate::transport::TransportRustls;
+
+/// Shortcut for `bb8::Pool` type of rustls-based CDRS connections.
+pub type RustlsConnectionPool<A> = ConnectionPool<RustlsConnectionsManager<A>>;
+
+/// `bb8::Pool` of rustls-based CDRS connections.
+///
+/// Pure-Rust alternative to [`new_ssl_pool`](super::new_ssl_pool). Enabled via the `rustls`
+/// cargo feature.
+pub async fn new_rustls_pool<'a, A: Authenticator + Send + Sync + 'static>(
+    node_config: NodeSslConfig<'a, A>,
+) -> error::Result<RustlsConnectionPool<A>> {
+    let manager = RustlsConnectionsManager::new(
+        node_config.addr,
+        node_config.authenticator,
+        node_config.root_certs.clone(),
+        node_config.handshake_timeout,
+    );
+
+    let pool = Builder::new()
+        .max_size(node_config.max_size)
+        .min_idle(node_config.min_idle)
+        .max_lifetime(node_config.max_lifetime)
+        .idle_timeout(node_config.idle_timeout)
+        .connection_timeout(node_config.connection_timeout)
+        .build(manager)
+        .await
+        .map_err(|err| error::Error::from(err.to_string()))?;
+
+    let addr = node_config
+        .addr
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| error::Error::from("Cannot parse address"))?;
+
+    Ok(RustlsConnectionPool::new(pool, addr))
+}
+
+/// `bb8` connection manager backed by `tokio-rustls` instead of OpenSSL.
+#[derive(Debug)]
+pub struct RustlsConnectionsManager<A> {
+    addr: String,
+    auth: A,
+    root_certs: RootCertSource,
+    handshake_timeout: Duration,
+}
+
+impl<A> RustlsConnectionsManager<A> {
+    pub fn new<S: ToString>(
+        addr: S,
+        auth: A,
+        root_certs: RootCertSource,
+        handshake_timeout: Duration,
+    ) -> Self {
+        RustlsConnectionsManager {
+            addr: addr.to_string(),
+            auth,
+            root_certs,
+            handshake_timeout,
+        }
+    }
+
+    /// Host portion of `addr`, i.e. without the trailing `:port`, used to build the
+    /// `ServerName` rustls verifies the presented certificate against.
+    fn host(&self) -> &str {
+        self.addr
+            .rsplit_once(':')
+            .map(|(host, _)| host)
+            .unwrap_or(self.addr.as_str())
+    }
+
+    fn client_config(&self) -> error::Result<ClientConfig> {
+        Ok(ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(self.root_certs.to_rustls_store()?)
+            .with_no_client_auth())
+    }
+}
+
+#[async_trait]
+impl<A: Authenticator + 'static + Send + Sync> ManageConnection for RustlsConnectionsManager<A> {
+    type Connection = Mutex<TransportRustls>;
+    type Error = error::Error;
+
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        tokio::time::timeout(self.handshake_timeout, async {
+            let config = Arc::new(self.client_config()?);
+            let connector = TlsConnector::from(config);
+
+            let socket_addr = self
+                .addr
+                .to_socket_addrs()?
+                .next()
+                .ok_or_else(|| error::Error::from("Cannot parse address"))?;
+            let tcp = TcpStream::connect(socket_addr).await?;
+
+            let server_name = ServerName::try_from(self.host())
+                .map_err(|_| error::Error::from(format!("Invalid DNS name: {}", self.host())))?;
+
+            let tls_stream = connector.connect(server_name, tcp).await?;
+            let transport = Mutex::new(TransportRustls::new(tls_stream));
+            startup(&transport, &self.auth).await?;
+
+            Ok(transport)
+        })
+        .await
+        .map_err(|_| error::Error::from("Timed out establishing a TLS connection to the node"))?
+    }
+
+    async fn is_valid(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+        let options_frame = Frame::new_req_options().into_cbytes();
+        conn.lock().await.write(options_frame.as_slice()).await?;
+
+        match parse_frame(&conn, &Compression::None {}).await {
+            Ok(_) => Ok(conn),
+            Err(err) => {
+                conn.lock().await.mark_broken();
+                Err(err)
+            }
+        }
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        conn.get_mut().is_broken()
+    }
+}
@@ -1,18 +1,19 @@
-use openssl::ssl::SslConnector;
+use openssl::ssl::{SslConnector, SslMethod};
 use r2d2::{Builder, ManageConnection, Pool};
 use std::cell::RefCell;
 use std::error::Error;
-use tokio::io::AsyncWriteExt;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use std::net::{SocketAddr, ToSocketAddrs};
 
 use crate::authenticators::Authenticator;
+use crate::cluster::multiplexer::MultiplexedConnection;
 use crate::cluster::ConnectionPool;
 use crate::cluster::{startup, NodeSslConfig};
 use crate::compression::Compression;
 use crate::error;
-use crate::frame::parser::parse_frame;
-use crate::frame::{Frame, IntoBytes};
+use crate::frame::Frame;
 use crate::transport::TransportTls;
 
 /// Shortcut for `bb8::Pool` type of SSL-based CDRS connections.
@@ -24,9 +25,15 @@ pub type SslConnectionPool<A> = ConnectionPool<SslConnectionsManager<A>>;
 pub async fn new_ssl_pool<'a, A: Authenticator + Send + Sync + 'static>(
     node_config: NodeSslConfig<'a, A>,
 ) -> error::Result<SslConnectionPool<A>> {
+    let mut connector_builder = SslConnector::builder(SslMethod::tls())?;
+    connector_builder.set_cert_store(node_config.root_certs.to_openssl_store()?);
+    let connector = Arc::new(connector_builder.build());
+
     let manager = SslConnectionsManager::new(
         node_config.addr,
         node_config.authenticator,
+        connector,
+        node_config.handshake_timeout,
     );
 
     let pool = Builder::new()
@@ -53,37 +60,50 @@ pub async fn new_ssl_pool<'a, A: Authenticator + Send + Sync + 'static>(
 pub struct SslConnectionsManager<A> {
     addr: String,
     auth: A,
+    connector: Arc<SslConnector>,
+    handshake_timeout: Duration,
 }
 
 impl<A> SslConnectionsManager<A> {
-    pub fn new<S: ToString>(addr: S, auth: A) -> Self {
+    pub fn new<S: ToString>(
+        addr: S,
+        auth: A,
+        connector: Arc<SslConnector>,
+        handshake_timeout: Duration,
+    ) -> Self {
         SslConnectionsManager {
             addr: addr.to_string(),
             auth,
+            connector,
+            handshake_timeout,
         }
     }
 }
 
 #[async_trait]
 impl<A: Authenticator + 'static + Send + Sync> ManageConnection for SslConnectionsManager<A> {
-    type Connection = Mutex<TransportTls>;
+    type Connection = MultiplexedConnection<TransportTls>;
     type Error = error::Error;
 
     async fn connect(&self) -> Result<Self::Connection, Self::Error> {
-        let transport = Mutex::new(TransportTls::new(&self.addr).await?);
-        startup(&transport, &self.auth).await?;
+        tokio::time::timeout(self.handshake_timeout, async {
+            let transport = Mutex::new(TransportTls::new(&self.addr, &self.connector).await?);
+            startup(&transport, &self.auth).await?;
 
-        Ok(transport)
+            let (connection, _events) =
+                MultiplexedConnection::new(transport.into_inner(), Compression::None {});
+            Ok(connection)
+        })
+        .await
+        .map_err(|_| error::Error::from("Timed out establishing a TLS connection to the node"))?
     }
 
     async fn is_valid(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
-        let options_frame = Frame::new_req_options().into_cbytes();
-        conn.lock().await.write(options_frame.as_slice()).await?;
-
-        parse_frame(&conn, &Compression::None {}).await.map(|_| conn)
+        conn.send(Frame::new_req_options()).await?;
+        Ok(conn)
     }
 
-    fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
-        false
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        conn.is_dead()
     }
 }
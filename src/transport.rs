@@ -0,0 +1,143 @@
+use std::pin::Pin;
+#[cfg(feature = "rustls")]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+
+#[cfg(feature = "openssl")]
+use openssl::ssl::SslConnector;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+#[cfg(feature = "openssl")]
+use tokio_openssl::SslStream;
+#[cfg(feature = "rustls")]
+use tokio_rustls::client::TlsStream;
+
+use crate::error;
+
+/// OpenSSL-backed transport to a Cassandra node. Enabled via the `openssl` cargo feature.
+///
+/// Liveness is tracked by the pooled [`MultiplexedConnection`](crate::cluster::multiplexer::MultiplexedConnection)
+/// wrapping this transport, not by the transport itself.
+#[cfg(feature = "openssl")]
+pub struct TransportTls {
+    stream: SslStream<TcpStream>,
+}
+
+#[cfg(feature = "openssl")]
+impl TransportTls {
+    /// Connects to `addr` and performs the TLS handshake using `connector`, whose trust store
+    /// is built from the pool's configured [`RootCertSource`](crate::cluster::RootCertSource).
+    pub async fn new(addr: &str, connector: &SslConnector) -> error::Result<Self> {
+        let tcp = TcpStream::connect(addr).await?;
+        let host = addr.rsplit_once(':').map(|(host, _)| host).unwrap_or(addr);
+
+        let ssl = connector.configure()?.into_ssl(host)?;
+        let mut stream = SslStream::new(ssl, tcp)?;
+        Pin::new(&mut stream).connect().await?;
+
+        Ok(TransportTls { stream })
+    }
+}
+
+#[cfg(feature = "openssl")]
+impl AsyncRead for TransportTls {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_read(cx, buf)
+    }
+}
+
+#[cfg(feature = "openssl")]
+impl AsyncWrite for TransportTls {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_shutdown(cx)
+    }
+}
+
+/// Pure-Rust, `tokio-rustls`-backed alternative to [`TransportTls`]. Enabled via the `rustls`
+/// cargo feature.
+///
+/// The TLS handshake is driven by the caller (see `RustlsConnectionsManager::connect`); this
+/// type only wraps the resulting stream.
+#[cfg(feature = "rustls")]
+pub struct TransportRustls {
+    stream: TlsStream<TcpStream>,
+    broken: AtomicBool,
+}
+
+#[cfg(feature = "rustls")]
+impl TransportRustls {
+    /// Wraps an already-established rustls client stream.
+    pub fn new(stream: TlsStream<TcpStream>) -> Self {
+        TransportRustls {
+            stream,
+            broken: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns `true` if a prior read or write on this transport has failed, or the pool's
+    /// `is_valid` check could not parse a reply from it; the pool then evicts the connection
+    /// instead of handing it back out.
+    pub fn is_broken(&self) -> bool {
+        self.broken.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn mark_broken(&self) {
+        self.broken.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "rustls")]
+impl AsyncRead for TransportRustls {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.stream).poll_read(cx, buf);
+        if let Poll::Ready(Err(_)) = &result {
+            this.mark_broken();
+        }
+        result
+    }
+}
+
+#[cfg(feature = "rustls")]
+impl AsyncWrite for TransportRustls {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.stream).poll_write(cx, buf);
+        if let Poll::Ready(Err(_)) = &result {
+            this.mark_broken();
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_shutdown(cx)
+    }
+}